@@ -0,0 +1,208 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::resp::{BulkString, Value};
+
+/// Configuration of the [client-side cache](https://redis.io/docs/manual/client-side-caching/)
+/// used by [`MultiplexedClient::with_cache`](crate::MultiplexedClient::with_cache).
+///
+/// By default, the cache tracks every key read through a cached command (`GET`, `MGET`, `HGETALL`)
+/// in the default (non-broadcasting) tracking mode: the server remembers exactly which keys this
+/// connection has read and invalidates only those.
+///
+/// Calling [`bcast`](CacheConfig::bcast) switches to `CLIENT TRACKING ON BCAST`, where the server
+/// instead notifies the connection of writes to any key matching one of the configured
+/// [`prefixes`](CacheConfig::bcast), regardless of whether this connection ever read that key.
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    pub(crate) max_entries: usize,
+    pub(crate) ttl: Option<Duration>,
+    pub(crate) bcast: bool,
+    pub(crate) prefixes: Vec<String>,
+}
+
+impl CacheConfig {
+    /// Creates a new cache configuration with no entry limit, no TTL and default (non-broadcasting) tracking.
+    pub fn new() -> Self {
+        Self {
+            max_entries: usize::MAX,
+            ttl: None,
+            bcast: false,
+            prefixes: Vec::new(),
+        }
+    }
+
+    /// Caps the number of entries kept in the cache.
+    ///
+    /// Once the limit is reached, the oldest entry is evicted to make room for a new one.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Sets a time-to-live after which a cached entry is considered stale and refetched,
+    /// even if the server never sent an invalidation for it.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Switches tracking to `BCAST` mode, restricted to the given key prefix.
+    ///
+    /// Can be called multiple times to track several prefixes. An empty prefix tracks all keys.
+    pub fn bcast(mut self, prefix: impl Into<String>) -> Self {
+        self.bcast = true;
+        self.prefixes.push(prefix.into());
+        self
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct CacheEntry {
+    value: Value,
+    inserted_at: Instant,
+}
+
+/// An in-memory client-side cache for [`MultiplexedClient`](crate::MultiplexedClient).
+///
+/// Entries are populated on cache misses for `GET`, `MGET` and `HGETALL` and are evicted when
+/// their [`ttl`](CacheConfig::ttl) expires, when [`max_entries`](CacheConfig::max_entries) forces
+/// the oldest entry out, or when explicitly evicted through [`invalidate`](Self::invalidate).
+///
+/// `with_cache` issues `CLIENT TRACKING ON` so the server knows which keys this connection has
+/// read, and registers [`invalidate`](Self::invalidate) as the reader loop's invalidation
+/// callback, so RESP3 `__redis__:invalidate` push frames evict the matching entries automatically.
+/// [`invalidate`](Self::invalidate) remains `pub` for callers that observe writes some other way
+/// (e.g. a separate connection's write path), and [`ttl`](CacheConfig::ttl) bounds staleness for
+/// anything that neither path catches.
+///
+/// Because an explicit invalidation can race with a read for the same key still in flight, a key
+/// invalidated mid-flight is recorded in `in_flight_invalidations` and the pending reply is
+/// dropped instead of cached once it resolves.
+#[derive(Clone)]
+pub struct Cache {
+    config: CacheConfig,
+    entries: Arc<Mutex<HashMap<BulkString, CacheEntry>>>,
+    insertion_order: Arc<Mutex<Vec<BulkString>>>,
+    in_flight_invalidations: Arc<Mutex<std::collections::HashSet<BulkString>>>,
+}
+
+impl Cache {
+    pub(crate) fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            insertion_order: Arc::new(Mutex::new(Vec::new())),
+            in_flight_invalidations: Arc::new(Mutex::new(std::collections::HashSet::new())),
+        }
+    }
+
+    /// Builds the arguments following `CLIENT TRACKING` in the command issued right after the
+    /// connection handshake.
+    pub(crate) fn tracking_on_args(&self) -> Vec<BulkString> {
+        let mut args: Vec<BulkString> = vec!["ON".into()];
+
+        if self.config.bcast {
+            args.push("BCAST".into());
+
+            for prefix in &self.config.prefixes {
+                args.push("PREFIX".into());
+                args.push(prefix.as_str().into());
+            }
+        }
+
+        args
+    }
+
+    /// Returns a cached value for `key`, if present and not expired.
+    pub(crate) fn get(&self, key: &BulkString) -> Option<Value> {
+        let mut entries = self.entries.lock().unwrap();
+
+        let is_expired = match (&self.config.ttl, entries.get(key)) {
+            (Some(ttl), Some(entry)) => entry.inserted_at.elapsed() >= *ttl,
+            _ => false,
+        };
+
+        if is_expired {
+            entries.remove(key);
+            return None;
+        }
+
+        entries.get(key).map(|entry| entry.value.clone())
+    }
+
+    /// Marks `key` as about to be read, so an invalidation racing with the in-flight
+    /// request is not lost once the reply comes back.
+    pub(crate) fn begin_fetch(&self, key: &BulkString) {
+        self.in_flight_invalidations.lock().unwrap().remove(key);
+    }
+
+    /// Stores `value` for `key`, unless an invalidation for that key arrived while the
+    /// fetch that produced `value` was in flight.
+    pub(crate) fn insert(&self, key: BulkString, value: Value) {
+        if self.in_flight_invalidations.lock().unwrap().remove(&key) {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        let mut insertion_order = self.insertion_order.lock().unwrap();
+
+        // Refreshing an already-cached key (TTL refetch, repeated read) must not leave its old
+        // position behind, or `insertion_order` grows unbounded with stale entries and the
+        // "evict oldest" step below can end up popping a name that no longer maps to anything,
+        // letting `entries` exceed `max_entries` indefinitely.
+        insertion_order.retain(|k| k != &key);
+
+        if !entries.contains_key(&key) && entries.len() >= self.config.max_entries {
+            if let Some(oldest) = (!insertion_order.is_empty()).then(|| insertion_order.remove(0))
+            {
+                entries.remove(&oldest);
+            }
+        }
+
+        insertion_order.push(key.clone());
+        entries.insert(
+            key,
+            CacheEntry {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Evicts the given keys, or the whole cache when `keys` is `None`.
+    ///
+    /// This mirrors what a RESP3 `__redis__:invalidate` push message carries (the list of
+    /// invalidated keys, or `null` meaning the server flushed its whole tracking table).
+    /// [`MultiplexedClient::with_cache`](crate::MultiplexedClient::with_cache) registers this as
+    /// the reader loop's invalidation callback, but it remains `pub` so it can also be called
+    /// directly by code that observes writes some other way.
+    pub fn invalidate(&self, keys: Option<Vec<BulkString>>) {
+        match keys {
+            Some(keys) => {
+                let mut entries = self.entries.lock().unwrap();
+                let mut insertion_order = self.insertion_order.lock().unwrap();
+
+                for key in keys {
+                    if entries.remove(&key).is_none() {
+                        // Not cached yet: the matching read is still in flight.
+                        self.in_flight_invalidations.lock().unwrap().insert(key.clone());
+                    }
+                    insertion_order.retain(|k| k != &key);
+                }
+            }
+            None => {
+                self.entries.lock().unwrap().clear();
+                self.insertion_order.lock().unwrap().clear();
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    resp::{BulkString, Command, Value},
+    BitmapCommands, ClientTrait, ConnectionCommands, Future, GenericCommands, GeoCommands,
+    HashCommands, HyperLogLogCommands, InnerClient, InternalPubSubCommands, IntoConfig,
+    ListCommands, PubSubCommands, PubSubStream, Result, ScriptingCommands, SentinelCommands,
+    ServerCommands, SetCommands, SingleArgOrCollection, SortedSetCommands, StreamCommands,
+    StringCommands, Transaction,
+};
+
+#[cfg(feature = "mock")]
+use crate::MockClient;
+
+enum Executor {
+    Inner(InnerClient),
+    #[cfg(feature = "mock")]
+    Mock(MockClient),
+}
+
+/// Groups multiple commands to be sent to the Redis server as a single round-trip.
+///
+/// Built with [`MultiplexedClient::create_pipeline`](crate::MultiplexedClient::create_pipeline) or,
+/// for offline tests, [`MockClient::create_pipeline`](crate::MockClient::create_pipeline).
+pub struct Pipeline {
+    executor: Executor,
+    commands: Vec<Command>,
+}
+
+impl Pipeline {
+    pub(crate) fn new(inner_client: InnerClient) -> Self {
+        Self {
+            executor: Executor::Inner(inner_client),
+            commands: Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    pub(crate) fn new_mocked(mock_client: MockClient) -> Self {
+        Self {
+            executor: Executor::Mock(mock_client),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Queues `command` to be sent as part of this pipeline.
+    pub fn queue(&mut self, command: Command) -> &mut Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Sends every queued command in a single batch and returns the raw array of replies,
+    /// in the order the commands were queued.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn execute(self) -> Result<Value> {
+        match self.executor {
+            Executor::Inner(mut inner_client) => inner_client.send_batch(self.commands).await,
+            #[cfg(feature = "mock")]
+            Executor::Mock(mut mock_client) => mock_client.send_batch(self.commands).await,
+        }
+    }
+}
+
+/// A single, non-multiplexed connection to a Redis server.
+///
+/// Unlike [`MultiplexedClient`](crate::MultiplexedClient), a `Client` does not share its
+/// connection with any clone, which is what lets it support
+/// [`TransactionCommands::watch`](crate::TransactionCommands::watch) /
+/// [`unwatch`](crate::TransactionCommands::unwatch) inside a
+/// [`Transaction`](crate::Transaction) and [blocking commands](crate::BlockingCommands).
+pub struct Client {
+    inner_client: InnerClient,
+}
+
+impl Client {
+    /// Connects asynchronously to a Redis server.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the connection operation
+    pub async fn connect(config: impl IntoConfig) -> Result<Self> {
+        Ok(Self {
+            inner_client: InnerClient::connect(config).await?,
+        })
+    }
+
+    /// Send an arbitrary command to the Redis server.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn send(&mut self, command: Command) -> Result<Value> {
+        self.inner_client.send(command).await
+    }
+
+    /// Send command to the Redis server and forget its response.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub fn send_and_forget(&mut self, command: Command) -> Result<()> {
+        self.inner_client.send_and_forget(command)
+    }
+
+    /// Send a command batch to the Redis server.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn send_batch(&mut self, commands: Vec<Command>) -> Result<Value> {
+        self.inner_client.send_batch(commands).await
+    }
+
+    /// Create a new pipeline
+    pub fn create_pipeline(&mut self) -> Pipeline {
+        Pipeline::new(self.inner_client.clone())
+    }
+
+    /// Create a new transaction
+    pub fn create_transaction(&mut self) -> Transaction {
+        Transaction::new(self.inner_client.clone())
+    }
+}
+
+impl ClientTrait for Client {
+    fn send(&mut self, command: Command) -> Future<Value> {
+        let mut inner_client = self.inner_client.clone();
+        Box::pin(async move { inner_client.send(command).await })
+    }
+
+    fn send_and_forget(&mut self, command: Command) -> Result<()> {
+        self.inner_client.send_and_forget(command)
+    }
+
+    fn send_batch(&mut self, commands: Vec<Command>) -> Future<Value> {
+        let mut inner_client = self.inner_client.clone();
+        Box::pin(async move { inner_client.send_batch(commands).await })
+    }
+
+    fn create_pipeline(&mut self) -> Pipeline {
+        Pipeline::new(self.inner_client.clone())
+    }
+}
+
+impl BitmapCommands for Client {}
+impl ConnectionCommands for Client {}
+impl GenericCommands for Client {}
+impl GeoCommands for Client {}
+impl HashCommands for Client {}
+impl HyperLogLogCommands for Client {}
+impl InternalPubSubCommands for Client {}
+impl ListCommands for Client {}
+impl ScriptingCommands for Client {}
+impl SentinelCommands for Client {}
+impl ServerCommands for Client {}
+impl SetCommands for Client {}
+impl SortedSetCommands for Client {}
+impl StreamCommands for Client {}
+impl StringCommands for Client {}
+
+#[cfg(feature = "redis-json")]
+impl crate::JsonCommands for Client {}
+#[cfg(feature = "redis-search")]
+impl crate::SearchCommands for Client {}
+#[cfg(feature = "redis-time-series")]
+impl crate::TimeSeriesCommands for Client {}
+#[cfg(feature = "redis-bloom")]
+impl crate::BloomCommands for Client {}
+#[cfg(feature = "redis-bloom")]
+impl crate::CuckooCommands for Client {}
+#[cfg(feature = "redis-bloom")]
+impl crate::CountMinSketchCommands for Client {}
+#[cfg(feature = "redis-bloom")]
+impl crate::TDigestCommands for Client {}
+#[cfg(feature = "redis-bloom")]
+impl crate::TopKCommands for Client {}
+
+impl PubSubCommands for Client {
+    fn subscribe<'a, C, CC>(&'a mut self, channels: CC) -> Future<'a, PubSubStream>
+    where
+        C: Into<BulkString> + Send + 'a,
+        CC: SingleArgOrCollection<C>,
+    {
+        self.inner_client.subscribe(channels)
+    }
+
+    fn psubscribe<'a, P, PP>(&'a mut self, patterns: PP) -> Future<'a, PubSubStream>
+    where
+        P: Into<BulkString> + Send + 'a,
+        PP: SingleArgOrCollection<P>,
+    {
+        self.inner_client.psubscribe(patterns)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    resp::{Command, Value},
+    Future, Pipeline, Result,
+};
+
+/// An object-safe abstraction over the common send/pipeline surface of a Redis client, so library
+/// code can be written once against `&mut dyn ClientTrait` instead of being generic over (or
+/// locked to) one concrete client.
+///
+/// Implemented by [`MultiplexedClient`](crate::MultiplexedClient), [`Client`](crate::Client) and
+/// [`PooledClientManager`](crate::PooledClientManager), so all three can be stored behind the same
+/// trait object regardless of their very different connection-sharing strategies.
+///
+/// Command trait default methods (e.g. [`StringCommands::get`](crate::StringCommands::get)) are
+/// themselves generic over their return type through [`FromValue`](crate::resp::FromValue) and
+/// are therefore not object-safe; callers needing dynamic dispatch should build the [`Command`]
+/// with [`resp::cmd`](crate::resp::cmd) and go through [`send`](ClientTrait::send) directly.
+///
+/// # Example
+/// ```
+/// use redis_driver::{resp::cmd, ClientTrait, Result};
+///
+/// async fn warm_cache(client: &mut dyn ClientTrait) -> Result<()> {
+///     client.send(cmd("GET").arg("key")).await?;
+///     Ok(())
+/// }
+/// ```
+pub trait ClientTrait: Send {
+    /// Send an arbitrary command to the Redis server.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    fn send(&mut self, command: Command) -> Future<Value>;
+
+    /// Send command to the Redis server and forget its response.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    fn send_and_forget(&mut self, command: Command) -> Result<()>;
+
+    /// Send a command batch to the Redis server.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    fn send_batch(&mut self, commands: Vec<Command>) -> Future<Value>;
+
+    /// Create a new pipeline
+    fn create_pipeline(&mut self) -> Pipeline;
+}
@@ -0,0 +1,153 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    resp::{Command, Value},
+    BitmapCommands, ClientTrait, ConnectionCommands, Error, Future, GenericCommands, GeoCommands,
+    HashCommands, HyperLogLogCommands, InternalPubSubCommands, ListCommands, Pipeline, Result,
+    ScriptingCommands, SentinelCommands, ServerCommands, SetCommands, SortedSetCommands,
+    StreamCommands, StringCommands,
+};
+
+/// How a [`MockClient`] resolves a [`Command`] it has no registered expectation for.
+pub enum UnmatchedCommandBehavior {
+    /// Resolve with the given [`Value`].
+    Default(Value),
+    /// Fail the send with the given [`Error`].
+    Error(Error),
+}
+
+type Responder = Box<dyn Fn(&Command) -> Option<Value> + Send + Sync>;
+
+/// An in-process, programmable stand-in for [`MultiplexedClient`](crate::MultiplexedClient) that
+/// resolves commands against a response table instead of a live socket, so downstream crates can
+/// unit-test their Redis logic deterministically in CI, including pipelines built with
+/// [`create_pipeline`](Self::create_pipeline).
+///
+/// Pub/sub subscribe streams are not supported yet: [`MockClient`] does not implement
+/// [`PubSubCommands`](crate::PubSubCommands).
+///
+/// # Example
+/// ```
+/// use redis_driver::{resp::{cmd, Value}, MockClient, ClientTrait};
+///
+/// # #[tokio::main]
+/// # async fn main() -> redis_driver::Result<()> {
+/// let mut mock = MockClient::new();
+/// mock.expect(cmd("GET").arg("key"), Value::BulkString("value".into()));
+///
+/// let value = mock.send(cmd("GET").arg("key")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MockClient {
+    expectations: Arc<Mutex<Vec<(Command, Value)>>>,
+    responder: Arc<Mutex<Option<Responder>>>,
+    unmatched: Arc<Mutex<UnmatchedCommandBehavior>>,
+}
+
+impl MockClient {
+    /// Creates a mock client with no registered expectations.
+    ///
+    /// Unmatched commands resolve to [`Value::Nil`] by default; configure a different behavior
+    /// with [`on_unmatched`](Self::on_unmatched).
+    pub fn new() -> Self {
+        Self {
+            expectations: Arc::new(Mutex::new(Vec::new())),
+            responder: Arc::new(Mutex::new(None)),
+            unmatched: Arc::new(Mutex::new(UnmatchedCommandBehavior::Default(Value::Nil))),
+        }
+    }
+
+    /// Registers an expected `command` and the `response` it should resolve to.
+    ///
+    /// Matching commands are consumed in registration order: each expectation answers a single
+    /// send, so the same command sent twice needs two registrations, or the second send falls
+    /// through to the [`on_unmatched`](Self::on_unmatched) behavior.
+    pub fn expect(&mut self, command: Command, response: Value) {
+        self.expectations.lock().unwrap().push((command, response));
+    }
+
+    /// Sets a closure that maps any incoming [`Command`] to a [`Value`], replacing any previously
+    /// set closure and taking priority over commands registered with [`expect`](Self::expect).
+    /// When the closure returns `None`, those registered expectations are consulted as usual.
+    pub fn expect_fn<F>(&mut self, responder: F)
+    where
+        F: Fn(&Command) -> Option<Value> + Send + Sync + 'static,
+    {
+        *self.responder.lock().unwrap() = Some(Box::new(responder));
+    }
+
+    /// Sets how commands with no matching expectation are resolved.
+    pub fn on_unmatched(&mut self, behavior: UnmatchedCommandBehavior) {
+        *self.unmatched.lock().unwrap() = behavior;
+    }
+
+    fn resolve(&self, command: &Command) -> Result<Value> {
+        if let Some(responder) = self.responder.lock().unwrap().as_ref() {
+            if let Some(value) = responder(command) {
+                return Ok(value);
+            }
+        }
+
+        let mut expectations = self.expectations.lock().unwrap();
+        if let Some(position) = expectations.iter().position(|(expected, _)| expected == command) {
+            let (_, value) = expectations.remove(position);
+            return Ok(value);
+        }
+        drop(expectations);
+
+        match &*self.unmatched.lock().unwrap() {
+            UnmatchedCommandBehavior::Default(value) => Ok(value.clone()),
+            UnmatchedCommandBehavior::Error(error) => Err(error.clone()),
+        }
+    }
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClientTrait for MockClient {
+    fn send(&mut self, command: Command) -> Future<Value> {
+        let client = self.clone();
+        Box::pin(async move { client.resolve(&command) })
+    }
+
+    fn send_and_forget(&mut self, command: Command) -> Result<()> {
+        self.resolve(&command).map(|_| ())
+    }
+
+    fn send_batch(&mut self, commands: Vec<Command>) -> Future<Value> {
+        let client = self.clone();
+        Box::pin(async move {
+            let values = commands
+                .iter()
+                .map(|command| client.resolve(command))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Value::Array(values))
+        })
+    }
+
+    fn create_pipeline(&mut self) -> Pipeline {
+        Pipeline::new_mocked(self.clone())
+    }
+}
+
+impl BitmapCommands for MockClient {}
+impl ConnectionCommands for MockClient {}
+impl GenericCommands for MockClient {}
+impl GeoCommands for MockClient {}
+impl HashCommands for MockClient {}
+impl HyperLogLogCommands for MockClient {}
+impl InternalPubSubCommands for MockClient {}
+impl ListCommands for MockClient {}
+impl ScriptingCommands for MockClient {}
+impl SentinelCommands for MockClient {}
+impl ServerCommands for MockClient {}
+impl SetCommands for MockClient {}
+impl SortedSetCommands for MockClient {}
+impl StreamCommands for MockClient {}
+impl StringCommands for MockClient {}
@@ -1,13 +1,27 @@
+mod cache;
 mod client;
+mod client_trait;
 mod message;
+#[cfg(feature = "mock")]
+mod mock_client;
+#[cfg(feature = "redis-cluster")]
+mod multiplexed_cluster_client;
 #[cfg(feature = "pool")]
 mod pooled_client_manager;
 mod pub_sub_stream;
+mod timeout_config;
 mod transaction;
 
+pub use cache::*;
 pub use client::*;
+pub use client_trait::*;
 pub(crate) use message::*;
+#[cfg(feature = "mock")]
+pub use mock_client::*;
+#[cfg(feature = "redis-cluster")]
+pub use multiplexed_cluster_client::*;
 #[cfg(feature = "pool")]
 pub use pooled_client_manager::*;
 pub use pub_sub_stream::*;
+pub use timeout_config::*;
 pub use transaction::*;
\ No newline at end of file
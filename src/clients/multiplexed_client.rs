@@ -1,11 +1,15 @@
 use crate::{
-    resp::{BulkString, Command, FromValue, SingleArgOrCollection, Value},
-    BitmapCommands, ConnectionCommands, Future, GenericCommands, GeoCommands, HashCommands,
-    HyperLogLogCommands, InnerClient, InternalPubSubCommands, IntoConfig, ListCommands, Pipeline,
-    PreparedCommand, PubSubCommands, PubSubStream, Result, ScriptingCommands, SentinelCommands,
-    ServerCommands, SetCommands, SortedSetCommands, StreamCommands, StringCommands, Transaction,
+    resp::{cmd, BulkString, Command, FromValue, SingleArgOrCollection, Value},
+    BitmapCommands, Cache, CacheConfig, ClientTrait, ConnectionCommands, Future, GenericCommands,
+    GeoCommands, HashCommands, HyperLogLogCommands, InnerClient, InternalPubSubCommands,
+    IntoConfig, ListCommands, Pipeline, PreparedCommand, PubSubCommands, PubSubStream, Result,
+    ScriptingCommands, SentinelCommands, ServerCommands, SetCommands, SortedSetCommands,
+    StreamCommands, StringCommands, Transaction,
+};
+use std::{
+    future::IntoFuture,
+    sync::{Arc, Mutex},
 };
-use std::future::IntoFuture;
 
 /// A multiplexed client that can be cloned, allowing requests
 /// to be be sent concurrently on the same underlying connection.
@@ -20,7 +24,21 @@ use std::future::IntoFuture;
 /// #See also [Multiplexing Explained](https://redis.com/blog/multiplexing-explained/)
 #[derive(Clone)]
 pub struct MultiplexedClient {
-    inner_client: InnerClient,
+    /// Shared so that a reconnection triggered through any one clone (directly, or through
+    /// [`send`](Self::send) on a timeout/connection error) is immediately visible to every other
+    /// clone of this client, instead of only updating the clone that happened to observe the failure.
+    inner_client: Arc<Mutex<InnerClient>>,
+    cache: Option<Cache>,
+    config: Option<(crate::Config, TimeoutConfig)>,
+    /// Channels and patterns subscribed to through this client (or a clone of it), replayed
+    /// against the new connection every time [`reconnect`](Self::reconnect) succeeds.
+    subscriptions: Arc<Mutex<Subscriptions>>,
+}
+
+#[derive(Default)]
+struct Subscriptions {
+    channels: Vec<BulkString>,
+    patterns: Vec<BulkString>,
 }
 
 impl MultiplexedClient {
@@ -30,7 +48,290 @@ impl MultiplexedClient {
     /// Any Redis driver [`Error`](crate::Error) that occurs during the connection operation
     pub async fn connect(config: impl IntoConfig) -> Result<Self> {
         let inner_client = InnerClient::connect(config).await?;
-        Ok(Self { inner_client })
+        Ok(Self {
+            inner_client: Arc::new(Mutex::new(inner_client)),
+            cache: None,
+            config: None,
+            subscriptions: Arc::new(Mutex::new(Subscriptions::default())),
+        })
+    }
+
+    /// Returns a cheap clone of the `InnerClient` handle currently in use, without holding the
+    /// lock across any `await` point.
+    fn current_inner_client(&self) -> InnerClient {
+        self.inner_client.lock().unwrap().clone()
+    }
+
+    /// Connects asynchronously to the Redis server with connection/command timeouts and an
+    /// automatic reconnection policy, as described by `timeout_config`.
+    ///
+    /// When a command exceeds its [`command_timeout`](TimeoutConfig::command_timeout) or the
+    /// underlying connection drops, [`send`](Self::send) fails with
+    /// [`Error::Timeout`](crate::Error::Timeout) or the connection error, unless
+    /// [`reconnection_policy`](TimeoutConfig::reconnection_policy) allows retries: the client then
+    /// transparently re-establishes the socket, re-runs `HELLO`/`AUTH`/`SELECT`, and sends the
+    /// command once more on the new connection, returning that result. Because the
+    /// underlying connection handle is shared behind the scenes, every clone of this client
+    /// observes the reconnected socket, not just the clone that happened to hit the error.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the connection operation
+    pub async fn connect_with_config(
+        config: impl IntoConfig,
+        timeout_config: TimeoutConfig,
+    ) -> Result<Self> {
+        let config = config.into_config()?;
+
+        let inner_client = tokio::time::timeout(
+            timeout_config.connection_timeout,
+            InnerClient::connect(config.clone()),
+        )
+        .await
+        .map_err(|_| crate::Error::Timeout)??;
+
+        Ok(Self {
+            inner_client: Arc::new(Mutex::new(inner_client)),
+            cache: None,
+            config: Some((config, timeout_config)),
+            subscriptions: Arc::new(Mutex::new(Subscriptions::default())),
+        })
+    }
+
+    /// Re-establishes the underlying connection, re-running `HELLO`/`AUTH`/`SELECT`, following
+    /// the [`ReconnectionPolicy`] this client was built with via
+    /// [`connect_with_config`](Self::connect_with_config).
+    ///
+    /// Because the underlying connection handle is shared between every clone made from the same
+    /// original client, reconnecting through one clone (directly, or automatically via
+    /// [`send`](Self::send) on a timeout or a connection error) is immediately visible to all the
+    /// others — they keep sending through the same, now-reconnected, socket.
+    ///
+    /// Every channel and pattern previously passed to [`subscribe`](PubSubCommands::subscribe) /
+    /// [`psubscribe`](PubSubCommands::psubscribe) on this client (or a clone of it) is re-issued
+    /// against the new connection, so the server's subscription state is restored. This re-sends
+    /// the `SUBSCRIBE`/`PSUBSCRIBE` commands but, since a [`PubSubStream`] is tied to the specific
+    /// connection it was created from, any `PubSubStream` obtained before this reconnect will not
+    /// itself start yielding messages again — call `subscribe`/`psubscribe` once more to get a
+    /// stream bound to the live connection.
+    ///
+    /// # Errors
+    /// [`Error::NotConfiguredForReconnection`](crate::Error::NotConfiguredForReconnection) if this
+    /// client was not built with [`connect_with_config`](Self::connect_with_config), or any
+    /// Redis driver [`Error`](crate::Error) that occurs while reconnecting.
+    pub async fn reconnect(&self) -> Result<()> {
+        let Some((config, timeout_config)) = &self.config else {
+            return Err(crate::Error::NotConfiguredForReconnection);
+        };
+
+        let policy = &timeout_config.reconnection_policy;
+        let mut last_error = None;
+
+        for attempt in 0..=policy.max_attempts {
+            if attempt > 0 {
+                tokio::time::sleep(policy.delay_for_attempt(attempt - 1)).await;
+            }
+
+            match tokio::time::timeout(
+                timeout_config.connection_timeout,
+                InnerClient::connect(config.clone()),
+            )
+            .await
+            {
+                Ok(Ok(inner_client)) => {
+                    *self.inner_client.lock().unwrap() = inner_client;
+                    self.resubscribe().await;
+                    return Ok(());
+                }
+                Ok(Err(error)) => last_error = Some(error),
+                Err(_) => last_error = Some(crate::Error::Timeout),
+            }
+        }
+
+        Err(last_error.unwrap_or(crate::Error::Timeout))
+    }
+
+    /// Re-issues `SUBSCRIBE`/`PSUBSCRIBE` for every channel and pattern recorded by a prior
+    /// [`subscribe`](PubSubCommands::subscribe)/[`psubscribe`](PubSubCommands::psubscribe) call,
+    /// against whatever connection is current. Best-effort: a failure here is not surfaced,
+    /// since it would otherwise turn a successful reconnect into a failed one over a step that is
+    /// itself retried on the next reconnect.
+    async fn resubscribe(&self) {
+        let (channels, patterns) = {
+            let subscriptions = self.subscriptions.lock().unwrap();
+            (subscriptions.channels.clone(), subscriptions.patterns.clone())
+        };
+
+        let mut inner_client = self.current_inner_client();
+
+        if !channels.is_empty() {
+            let _ = inner_client.subscribe(channels).await;
+        }
+        if !patterns.is_empty() {
+            let _ = inner_client.psubscribe(patterns).await;
+        }
+    }
+
+    /// Connects asynchronously to the Redis server and enables
+    /// [client-side caching](https://redis.io/docs/manual/client-side-caching/) for
+    /// `GET`, `MGET` and `HGETALL` reads, served through [`get_cached`](Self::get_cached),
+    /// [`mget_cached`](Self::mget_cached) and [`hgetall_cached`](Self::hgetall_cached).
+    ///
+    /// This issues `CLIENT TRACKING ON` right after the handshake and registers an invalidation
+    /// callback with [`InnerClient`]'s reader loop, so every RESP3 `__redis__:invalidate` push
+    /// frame the server sends for this connection's tracked keys evicts the matching
+    /// [`Cache`] entries automatically — no manual [`invalidate`](Cache::invalidate) call needed
+    /// for writes that go through this same connection's tracking table. The cache is additionally
+    /// bounded by [`CacheConfig::ttl`] and [`CacheConfig::max_entries`], and can still be evicted
+    /// directly through [`cache`](Self::cache) (e.g. for writes observed out of band).
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the connection operation
+    pub async fn with_cache(config: impl IntoConfig, cache_config: CacheConfig) -> Result<Self> {
+        let inner_client = InnerClient::connect(config).await?;
+        let cache = Cache::new(cache_config);
+
+        let mut tracking_on = cmd("CLIENT").arg("TRACKING");
+        for arg in cache.tracking_on_args() {
+            tracking_on = tracking_on.arg(arg);
+        }
+        inner_client.clone().send(tracking_on).await?;
+
+        let invalidation_cache = cache.clone();
+        inner_client
+            .clone()
+            .set_invalidation_callback(Box::new(move |keys| invalidation_cache.invalidate(keys)));
+
+        Ok(Self {
+            inner_client: Arc::new(Mutex::new(inner_client)),
+            cache: Some(cache),
+            config: None,
+            subscriptions: Arc::new(Mutex::new(Subscriptions::default())),
+        })
+    }
+
+    /// Returns a handle to the client-side [`Cache`] when this client was built with
+    /// [`with_cache`](Self::with_cache), so callers can evict entries directly, e.g. for writes
+    /// observed out of band that this connection's tracking does not report.
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
+    /// Reads `key`, like [`StringCommands::get`](crate::StringCommands::get), but serves the
+    /// value from the client-side [`Cache`] when present and valid, and populates the cache on a miss.
+    ///
+    /// Falls back to a plain, uncached `GET` if the client was not built with
+    /// [`with_cache`](Self::with_cache).
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn get_cached<K>(&mut self, key: K) -> Result<Value>
+    where
+        K: Into<BulkString> + Send,
+    {
+        let key = key.into();
+
+        if let Some(cache) = &self.cache {
+            if let Some(value) = cache.get(&key) {
+                return Ok(value);
+            }
+            cache.begin_fetch(&key);
+        }
+
+        let value = self
+            .current_inner_client()
+            .send(cmd("GET").arg(key.clone()))
+            .await?;
+
+        if let Some(cache) = &self.cache {
+            cache.insert(key, value.clone());
+        }
+
+        Ok(value)
+    }
+
+    /// Reads `keys`, like [`StringCommands::mget`](crate::StringCommands::mget), serving each key
+    /// from the client-side [`Cache`] when possible and only fetching the still-missing keys from
+    /// the server, in original order.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn mget_cached<K, KK>(&mut self, keys: KK) -> Result<Value>
+    where
+        K: Into<BulkString> + Send,
+        KK: SingleArgOrCollection<K>,
+    {
+        let keys: Vec<BulkString> = keys.into_args(Vec::new()).into_iter().map(Into::into).collect();
+
+        let Some(cache) = self.cache.clone() else {
+            let mut command = cmd("MGET");
+            for key in keys {
+                command = command.arg(key);
+            }
+            return self.current_inner_client().send(command).await;
+        };
+
+        let mut values = Vec::with_capacity(keys.len());
+        let mut missing = Vec::new();
+
+        for key in &keys {
+            match cache.get(key) {
+                Some(value) => values.push(Some(value)),
+                None => {
+                    cache.begin_fetch(key);
+                    missing.push(key.clone());
+                    values.push(None);
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            let mut command = cmd("MGET");
+            for key in &missing {
+                command = command.arg(key.clone());
+            }
+            let fetched: Vec<Value> = self.current_inner_client().send(command).await?.into()?;
+
+            let mut fetched = fetched.into_iter();
+            let missing_slots = values.iter_mut().filter(|slot| slot.is_none());
+            for (key, slot) in missing.iter().zip(missing_slots) {
+                if let Some(value) = fetched.next() {
+                    cache.insert(key.clone(), value.clone());
+                    *slot = Some(value);
+                }
+            }
+        }
+
+        Ok(Value::Array(values.into_iter().flatten().collect()))
+    }
+
+    /// Reads all fields of `key`, like [`HashCommands::hgetall`](crate::HashCommands::hgetall),
+    /// serving the result from the client-side [`Cache`] when present and valid.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn hgetall_cached<K>(&mut self, key: K) -> Result<Value>
+    where
+        K: Into<BulkString> + Send,
+    {
+        let key = key.into();
+
+        if let Some(cache) = &self.cache {
+            if let Some(value) = cache.get(&key) {
+                return Ok(value);
+            }
+            cache.begin_fetch(&key);
+        }
+
+        let value = self
+            .current_inner_client()
+            .send(cmd("HGETALL").arg(key.clone()))
+            .await?;
+
+        if let Some(cache) = &self.cache {
+            cache.insert(key, value.clone());
+        }
+
+        Ok(value)
     }
 
     /// Send an arbitrary command to the Redis server.
@@ -63,7 +364,42 @@ impl MultiplexedClient {
     /// }
     /// ```
     pub async fn send(&mut self, command: Command) -> Result<Value> {
-        self.inner_client.send(command).await
+        self.send_with_policy(command).await
+    }
+
+    /// Shared implementation behind the inherent [`send`](Self::send) and the
+    /// [`ClientTrait`] impl below, so code written against `&mut dyn ClientTrait` gets the exact
+    /// same command timeout and reconnection behavior as code holding a concrete
+    /// `MultiplexedClient`.
+    async fn send_with_policy(&self, command: Command) -> Result<Value> {
+        let Some((_, timeout_config)) = &self.config else {
+            return self.current_inner_client().send(command).await;
+        };
+
+        let result = match timeout_config.command_timeout {
+            Some(command_timeout) => {
+                match tokio::time::timeout(
+                    command_timeout,
+                    self.current_inner_client().send(command.clone()),
+                )
+                .await
+                {
+                    Ok(result) => result,
+                    Err(_) => Err(crate::Error::Timeout),
+                }
+            }
+            None => self.current_inner_client().send(command.clone()).await,
+        };
+
+        match result {
+            Err(crate::Error::Timeout | crate::Error::Connection(_))
+                if timeout_config.reconnection_policy.max_attempts > 0 =>
+            {
+                self.reconnect().await?;
+                self.current_inner_client().send(command).await
+            }
+            other => other,
+        }
     }
 
     /// Send command to the Redis server and forget its response.
@@ -71,7 +407,7 @@ impl MultiplexedClient {
     /// # Errors
     /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
     pub fn send_and_forget(&mut self, command: Command) -> Result<()> {
-        self.inner_client.send_and_forget(command)
+        self.current_inner_client().send_and_forget(command)
     }
 
     /// Send a command batch to the Redis server.
@@ -79,25 +415,45 @@ impl MultiplexedClient {
     /// # Errors
     /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
     pub async fn send_batch(&mut self, commands: Vec<Command>) -> Result<Value> {
-        self.inner_client.send_batch(commands).await
+        self.current_inner_client().send_batch(commands).await
     }
 
     /// Create a new pipeline
     pub fn create_pipeline(&mut self) -> Pipeline {
-        Pipeline::new(self.inner_client.clone())
+        Pipeline::new(self.current_inner_client())
     }
 
     /// Create a new transaction
-    /// 
+    ///
     /// Because of the multiplexed nature of the client,
-    /// [`watch`](crate::TransactionCommands::watch) & 
+    /// [`watch`](crate::TransactionCommands::watch) &
     /// [`unwatch`](crate::TransactionCommands::unwatch)
     /// commands cannot be supported.
-    /// To be able to use these commands with a transaction, 
+    /// To be able to use these commands with a transaction,
     /// [`Client`](crate::Client) or [`PooledClientManager`](crate::PooledClientManager)
     /// should be used instead
     pub fn create_transaction(&mut self) -> Transaction {
-        Transaction::new(self.inner_client.clone())
+        Transaction::new(self.current_inner_client())
+    }
+}
+
+impl ClientTrait for MultiplexedClient {
+    fn send(&mut self, command: Command) -> Future<Value> {
+        let client = self.clone();
+        Box::pin(async move { client.send_with_policy(command).await })
+    }
+
+    fn send_and_forget(&mut self, command: Command) -> Result<()> {
+        self.current_inner_client().send_and_forget(command)
+    }
+
+    fn send_batch(&mut self, commands: Vec<Command>) -> Future<Value> {
+        let inner_client = self.current_inner_client();
+        Box::pin(async move { inner_client.send_batch(commands).await })
+    }
+
+    fn create_pipeline(&mut self) -> Pipeline {
+        Pipeline::new(self.current_inner_client())
     }
 }
 
@@ -153,13 +509,38 @@ impl SortedSetCommands for MultiplexedClient {}
 impl StreamCommands for MultiplexedClient {}
 impl StringCommands for MultiplexedClient {}
 
+#[cfg(feature = "redis-json")]
+impl crate::JsonCommands for MultiplexedClient {}
+#[cfg(feature = "redis-search")]
+impl crate::SearchCommands for MultiplexedClient {}
+#[cfg(feature = "redis-time-series")]
+impl crate::TimeSeriesCommands for MultiplexedClient {}
+#[cfg(feature = "redis-bloom")]
+impl crate::BloomCommands for MultiplexedClient {}
+#[cfg(feature = "redis-bloom")]
+impl crate::CuckooCommands for MultiplexedClient {}
+#[cfg(feature = "redis-bloom")]
+impl crate::CountMinSketchCommands for MultiplexedClient {}
+#[cfg(feature = "redis-bloom")]
+impl crate::TDigestCommands for MultiplexedClient {}
+#[cfg(feature = "redis-bloom")]
+impl crate::TopKCommands for MultiplexedClient {}
+
 impl PubSubCommands for MultiplexedClient {
     fn subscribe<'a, C, CC>(&'a mut self, channels: CC) -> Future<'a, PubSubStream>
     where
         C: Into<BulkString> + Send + 'a,
         CC: SingleArgOrCollection<C>,
     {
-        self.inner_client.subscribe(channels)
+        let resolved: Vec<BulkString> = channels
+            .into_args(Vec::new())
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        self.subscriptions.lock().unwrap().channels.extend(resolved.clone());
+
+        let mut inner_client = self.current_inner_client();
+        Box::pin(async move { inner_client.subscribe(resolved).await })
     }
 
     fn psubscribe<'a, P, PP>(&'a mut self, patterns: PP) -> Future<'a, PubSubStream>
@@ -167,6 +548,14 @@ impl PubSubCommands for MultiplexedClient {
         P: Into<BulkString> + Send + 'a,
         PP: SingleArgOrCollection<P>,
     {
-        self.inner_client.psubscribe(patterns)
+        let resolved: Vec<BulkString> = patterns
+            .into_args(Vec::new())
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        self.subscriptions.lock().unwrap().patterns.extend(resolved.clone());
+
+        let mut inner_client = self.current_inner_client();
+        Box::pin(async move { inner_client.psubscribe(resolved).await })
     }
 }
\ No newline at end of file
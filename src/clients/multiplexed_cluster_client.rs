@@ -0,0 +1,313 @@
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+use crate::{
+    resp::{cmd, Command, Value},
+    Error, InnerClient, IntoConfig, Result,
+};
+
+/// A 16384-slot Redis Cluster hash slot, as computed from a key with
+/// [`hash_slot`](MultiplexedClusterClient::hash_slot).
+pub type Slot = u16;
+
+const NUM_SLOTS: Slot = 16384;
+
+/// An async client that connects to a [Redis Cluster](https://redis.io/docs/management/scaling/),
+/// routing each command to the node that owns the hashed key slot.
+///
+/// Internally, `MultiplexedClusterClient` keeps one [`MultiplexedClient`](crate::MultiplexedClient)-like
+/// [`InnerClient`] per master node and a slot map fetched via `CLUSTER SHARDS` (`CLUSTER SLOTS`
+/// is not used). A `MOVED` redirection transparently re-resolves the slot map before retrying
+/// on the named node; an `ASK` redirection retries on the named node after issuing `ASKING`,
+/// without re-resolving the slot map.
+#[derive(Clone)]
+pub struct MultiplexedClusterClient {
+    state: Arc<RwLock<ClusterState>>,
+}
+
+struct ClusterState {
+    /// Ordered slot ranges, each mapped to the address of the node owning it.
+    slot_ranges: Vec<(Slot, Slot, String)>,
+    /// One multiplexed connection per master node address.
+    nodes: HashMap<String, InnerClient>,
+}
+
+impl MultiplexedClusterClient {
+    /// Connects asynchronously to a Redis Cluster, using `config` to reach any one of the seed nodes.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the connection operation
+    pub async fn connect(config: impl IntoConfig) -> Result<Self> {
+        let seed = InnerClient::connect(config).await?;
+        let mut state = ClusterState {
+            slot_ranges: Vec::new(),
+            nodes: HashMap::new(),
+        };
+        refresh_slot_map(&mut state, seed).await?;
+
+        Ok(Self {
+            state: Arc::new(RwLock::new(state)),
+        })
+    }
+
+    /// Computes the cluster hash slot of `key`.
+    ///
+    /// If `key` contains a `{hashtag}` substring, only the substring between the first `{` and the
+    /// first `}` *after* it is hashed, so that related keys can be routed to the same node — per
+    /// the cluster spec, a `}` before the first `{` (e.g. in `"a}{bc}d"`) does not count.
+    pub fn hash_slot(key: &[u8]) -> Slot {
+        let hashed = match key.iter().position(|&b| b == b'{') {
+            Some(open) => match key[open + 1..].iter().position(|&b| b == b'}') {
+                Some(len) if len > 0 => &key[open + 1..open + 1 + len],
+                _ => key,
+            },
+            None => key,
+        };
+
+        crc16(hashed) % NUM_SLOTS
+    }
+
+    /// Send an arbitrary command to the node owning the slot of `routing_key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation,
+    /// including `MOVED`/`ASK` redirections that could not be resolved.
+    pub async fn send(&mut self, routing_key: &[u8], command: Command) -> Result<Value> {
+        let slot = Self::hash_slot(routing_key);
+        self.send_to_slot(slot, command).await
+    }
+
+    /// Send a batch of commands, all of which must hash to the same slot when `atomic` is set.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation, or
+    /// [`Error::ClusterCrossSlot`](crate::Error::ClusterCrossSlot) when `atomic` is `true` and the
+    /// commands' routing keys span more than one slot.
+    pub async fn send_batch(
+        &mut self,
+        commands: Vec<(Vec<u8>, Command)>,
+        atomic: bool,
+    ) -> Result<Vec<Value>> {
+        let mut slots = std::collections::HashSet::new();
+        let mut by_address: HashMap<String, Vec<(usize, Command)>> = HashMap::new();
+
+        for (index, (routing_key, command)) in commands.into_iter().enumerate() {
+            let slot = Self::hash_slot(&routing_key);
+            slots.insert(slot);
+            let address = self.node_for_slot(slot).await?;
+            by_address.entry(address).or_default().push((index, command));
+        }
+
+        if atomic && slots.len() > 1 {
+            return Err(Error::ClusterCrossSlot);
+        }
+
+        let mut results = Vec::new();
+
+        // One real `InnerClient::send_batch` round trip per node, instead of one round trip per
+        // command, so commands sharing a node are actually pipelined together. A node that no
+        // longer owns any of the batched slots still needs the same `MOVED`/`ASK` handling
+        // `send_to_slot` gives a single command, or a pipeline straddling a live migration fails
+        // outright instead of redirecting.
+        for (address, indexed_commands) in by_address {
+            let (indices, commands): (Vec<usize>, Vec<Command>) =
+                indexed_commands.into_iter().unzip();
+            let mut inner_client = self.inner_client_for(&address).await?;
+
+            let values: Vec<Value> = match inner_client.send_batch(commands.clone()).await {
+                Err(Error::Moved { address, .. }) => {
+                    self.refresh().await?;
+                    let mut inner_client = self.inner_client_for(&address).await?;
+                    inner_client.send_batch(commands).await?.into()?
+                }
+                Err(Error::Ask { address, .. }) => {
+                    let mut inner_client = self.inner_client_for(&address).await?;
+                    inner_client.send(cmd("ASKING")).await?;
+                    inner_client.send_batch(commands).await?.into()?
+                }
+                other => other?.into()?,
+            };
+
+            results.extend(indices.into_iter().zip(values));
+        }
+
+        results.sort_by_key(|(index, _)| *index);
+        Ok(results.into_iter().map(|(_, value)| value).collect())
+    }
+
+    async fn send_to_slot(&mut self, slot: Slot, command: Command) -> Result<Value> {
+        let address = self.node_for_slot(slot).await?;
+        let mut inner_client = self.inner_client_for(&address).await?;
+
+        match inner_client.send(command.clone()).await {
+            Err(Error::Moved { address, .. }) => {
+                self.refresh().await?;
+                let mut inner_client = self.inner_client_for(&address).await?;
+                inner_client.send(command).await
+            }
+            Err(Error::Ask { address, .. }) => {
+                let mut inner_client = self.inner_client_for(&address).await?;
+                inner_client.send(cmd("ASKING")).await?;
+                inner_client.send(command).await
+            }
+            other => other,
+        }
+    }
+
+    async fn node_for_slot(&self, slot: Slot) -> Result<String> {
+        let state = self.state.read().await;
+        state
+            .slot_ranges
+            .iter()
+            .find(|(start, end, _)| slot >= *start && slot <= *end)
+            .map(|(_, _, address)| address.clone())
+            .ok_or(Error::ClusterSlotNotCovered(slot))
+    }
+
+    async fn inner_client_for(&self, address: &str) -> Result<InnerClient> {
+        if let Some(inner_client) = self.state.read().await.nodes.get(address) {
+            return Ok(inner_client.clone());
+        }
+
+        let inner_client = InnerClient::connect(address).await?;
+        self.state
+            .write()
+            .await
+            .nodes
+            .insert(address.to_owned(), inner_client.clone());
+        Ok(inner_client)
+    }
+
+    /// Re-fetches the cluster slot map from any currently known node.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the refresh operation
+    pub async fn refresh(&mut self) -> Result<()> {
+        let seed = {
+            let state = self.state.read().await;
+            state
+                .nodes
+                .values()
+                .next()
+                .cloned()
+                .ok_or(Error::ClusterSlotNotCovered(0))?
+        };
+
+        let mut state = self.state.write().await;
+        refresh_slot_map(&mut state, seed).await
+    }
+}
+
+async fn refresh_slot_map(state: &mut ClusterState, seed: InnerClient) -> Result<()> {
+    let mut seed = seed;
+    let shards: Value = seed.send(cmd("CLUSTER").arg("SHARDS")).await?;
+    let shards: Vec<Value> = shards.into()?;
+
+    state.slot_ranges.clear();
+
+    for shard in shards {
+        let (slots, nodes): (Vec<Slot>, Vec<Value>) = shard.into()?;
+        let Some(address) = master_address(nodes) else {
+            continue;
+        };
+
+        for range in slots.chunks_exact(2) {
+            state.slot_ranges.push((range[0], range[1], address.clone()));
+        }
+    }
+
+    state.nodes.entry(address_of(seed.clone())).or_insert(seed);
+
+    Ok(())
+}
+
+fn address_of(inner_client: InnerClient) -> String {
+    inner_client.address()
+}
+
+/// Picks the master's `ip:port` out of a `CLUSTER SHARDS` shard's `nodes` entry.
+///
+/// Each node is a flat `field value` list (`id`, `ip`, `port`, `role`, ...), not a bare address,
+/// and `CLUSTER SHARDS` does not guarantee the master is listed first, so every node must be
+/// inspected for the one with `role == "master"`. Returns `None` if no node in `nodes` is a
+/// parseable master entry, in which case the caller should skip the shard rather than route to a
+/// possibly-read-only replica.
+fn master_address(nodes: Vec<Value>) -> Option<String> {
+    nodes.into_iter().find_map(|node| {
+        let fields: Vec<Value> = node.into().ok()?;
+        let mut ip: Option<String> = None;
+        let mut port: Option<u16> = None;
+        let mut role: Option<String> = None;
+
+        for pair in fields.chunks_exact(2) {
+            let key: String = pair[0].clone().into().ok()?;
+            match key.as_str() {
+                "ip" => ip = pair[1].clone().into().ok(),
+                "port" => port = pair[1].clone().into().ok(),
+                "role" => role = pair[1].clone().into().ok(),
+                _ => {}
+            }
+        }
+
+        if role.as_deref() != Some("master") {
+            return None;
+        }
+
+        Some(format!("{}:{}", ip?, port?))
+    })
+}
+
+/// CRC16 (XMODEM) as specified by the [Redis Cluster spec](https://redis.io/docs/reference/cluster-spec/#key-distribution-model).
+fn crc16(data: &[u8]) -> u16 {
+    const POLY: u16 = 0x1021;
+    let mut crc: u16 = 0;
+
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ POLY
+            } else {
+                crc << 1
+            };
+        }
+    }
+
+    crc
+}
+
+/// A group of Redis commands for inspecting and managing the cluster topology itself,
+/// as opposed to routing ordinary commands to the node that owns their key.
+///
+/// # See Also
+/// [Cluster Command Reference](https://redis.io/commands/?group=cluster)
+pub trait ClusterCommands {
+    /// Returns human-readable information about the state of the cluster.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cluster-info/>]
+    fn cluster_info(&mut self) -> crate::Future<String>;
+
+    /// Forces this client to re-fetch the cluster slot map.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the refresh operation
+    fn cluster_refresh(&mut self) -> crate::Future<()>;
+}
+
+impl ClusterCommands for MultiplexedClusterClient {
+    fn cluster_info(&mut self) -> crate::Future<String> {
+        Box::pin(async move {
+            let address = self.node_for_slot(0).await?;
+            let mut inner_client = self.inner_client_for(&address).await?;
+            inner_client.send(cmd("CLUSTER").arg("INFO")).await?.into()
+        })
+    }
+
+    fn cluster_refresh(&mut self) -> crate::Future<()> {
+        Box::pin(async move { self.refresh().await })
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::{
+    resp::{Command, Value},
+    Client, ClientTrait, Future, InnerClient, IntoConfig, Pipeline, Result,
+};
+
+/// Configuration of a [`PooledClientManager`], governing how many idle [`Client`] connections it
+/// keeps around between checkouts.
+#[derive(Debug, Clone)]
+pub struct PooledClientManagerConfig {
+    max_idle: usize,
+}
+
+impl PooledClientManagerConfig {
+    /// Creates a new pool configuration keeping a single idle connection around.
+    pub fn new() -> Self {
+        Self { max_idle: 1 }
+    }
+
+    /// Caps the number of idle [`Client`] connections the pool keeps between checkouts. A checkout
+    /// that finds none idle always connects a fresh one rather than waiting, so this bounds memory
+    /// use, not concurrency.
+    pub fn max_idle(mut self, max_idle: usize) -> Self {
+        self.max_idle = max_idle;
+        self
+    }
+}
+
+impl Default for PooledClientManagerConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A pool of [`Client`] connections implementing [`ClientTrait`], so pooled access and
+/// [`MultiplexedClient`](crate::MultiplexedClient) access can be used interchangeably behind
+/// `&mut dyn ClientTrait`.
+///
+/// Each [`send`](ClientTrait::send)/[`send_batch`](ClientTrait::send_batch) checks out an idle
+/// [`Client`] (connecting a fresh one if none is idle), uses it, and returns it to the pool once
+/// the command resolves, so two in-flight commands never share the same exclusive connection the
+/// way two clones of a [`MultiplexedClient`] would.
+#[derive(Clone)]
+pub struct PooledClientManager {
+    config: crate::Config,
+    pool_config: PooledClientManagerConfig,
+    idle: Arc<AsyncMutex<Vec<Client>>>,
+    /// A cheap, shareable handle cloned into every [`Pipeline`] this manager creates. Unlike a
+    /// checked-out [`Client`], a pipeline holds its connection for its whole queue-then-execute
+    /// lifetime, so it is kept out of the idle pool's checkout/checkin bookkeeping entirely.
+    pipeline_inner_client: InnerClient,
+}
+
+impl PooledClientManager {
+    /// Connects a first, always-on connection to `config` (used to seed pipelines) and prepares a
+    /// pool of further [`Client`] connections, up to `pool_config.max_idle` kept idle at once.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the connection operation
+    pub async fn new(config: impl IntoConfig, pool_config: PooledClientManagerConfig) -> Result<Self> {
+        let config = config.into_config()?;
+        let pipeline_inner_client = InnerClient::connect(config.clone()).await?;
+
+        Ok(Self {
+            config,
+            pool_config,
+            idle: Arc::new(AsyncMutex::new(Vec::new())),
+            pipeline_inner_client,
+        })
+    }
+
+    async fn checkout(&self) -> Result<Client> {
+        if let Some(client) = self.idle.lock().await.pop() {
+            return Ok(client);
+        }
+
+        Client::connect(self.config.clone()).await
+    }
+
+    async fn checkin(&self, client: Client) {
+        let mut idle = self.idle.lock().await;
+        if idle.len() < self.pool_config.max_idle {
+            idle.push(client);
+        }
+    }
+}
+
+impl ClientTrait for PooledClientManager {
+    fn send(&mut self, command: Command) -> Future<Value> {
+        let manager = self.clone();
+        Box::pin(async move {
+            let mut client = manager.checkout().await?;
+            let result = client.send(command).await;
+            manager.checkin(client).await;
+            result
+        })
+    }
+
+    fn send_and_forget(&mut self, command: Command) -> Result<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            if let Ok(mut client) = manager.checkout().await {
+                let _ = client.send_and_forget(command);
+                manager.checkin(client).await;
+            }
+        });
+        Ok(())
+    }
+
+    fn send_batch(&mut self, commands: Vec<Command>) -> Future<Value> {
+        let manager = self.clone();
+        Box::pin(async move {
+            let mut client = manager.checkout().await?;
+            let result = client.send_batch(commands).await;
+            manager.checkin(client).await;
+            result
+        })
+    }
+
+    fn create_pipeline(&mut self) -> Pipeline {
+        Pipeline::new(self.pipeline_inner_client.clone())
+    }
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+/// Exponential backoff with jitter controlling how [`MultiplexedClient`](crate::MultiplexedClient)
+/// retries a dropped connection.
+#[derive(Debug, Clone)]
+pub struct ReconnectionPolicy {
+    pub(crate) max_attempts: usize,
+    pub(crate) initial_delay: Duration,
+    pub(crate) max_delay: Duration,
+}
+
+impl ReconnectionPolicy {
+    /// Creates a reconnection policy allowing up to `max_attempts` retries after the first
+    /// connection attempt, with an exponential backoff starting at `initial_delay`, doubling on
+    /// each retry up to `max_delay`.
+    ///
+    /// A random jitter of up to 20% of the computed delay is applied before each retry to
+    /// avoid a thundering herd of clients reconnecting in lockstep.
+    pub fn new(max_attempts: usize, initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            initial_delay,
+            max_delay,
+        }
+    }
+
+    /// A policy that never retries: the first failed attempt is returned to the caller.
+    pub fn disabled() -> Self {
+        Self::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    pub(crate) fn delay_for_attempt(&self, attempt: usize) -> Duration {
+        let exponential = self.initial_delay.saturating_mul(1 << attempt.min(16));
+        let capped = exponential.min(self.max_delay);
+        let jitter_fraction = pseudo_random_fraction() * 0.2;
+        capped.mul_f64(1.0 + jitter_fraction)
+    }
+}
+
+impl Default for ReconnectionPolicy {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+/// Connection and command timeouts, plus the [`ReconnectionPolicy`] used to recover from a
+/// stalled server or a dropped socket, as passed to
+/// [`MultiplexedClient::connect_with_config`](crate::MultiplexedClient::connect_with_config)
+/// alongside the regular [`Config`](crate::Config)/[`IntoConfig`](crate::IntoConfig) connection
+/// string.
+#[derive(Debug, Clone)]
+pub struct TimeoutConfig {
+    pub(crate) connection_timeout: Duration,
+    pub(crate) command_timeout: Option<Duration>,
+    pub(crate) reconnection_policy: ReconnectionPolicy,
+}
+
+impl TimeoutConfig {
+    /// Creates a configuration with a 5 second connection timeout, no command timeout and
+    /// reconnection disabled.
+    pub fn new() -> Self {
+        Self {
+            connection_timeout: Duration::from_secs(5),
+            command_timeout: None,
+            reconnection_policy: ReconnectionPolicy::disabled(),
+        }
+    }
+
+    /// Sets how long [`connect_with_config`](crate::MultiplexedClient::connect_with_config) waits
+    /// for the initial TCP connection and `HELLO` handshake before failing.
+    pub fn connection_timeout(mut self, timeout: Duration) -> Self {
+        self.connection_timeout = timeout;
+        self
+    }
+
+    /// Sets how long a single command may take to receive a response before it is failed with
+    /// [`Error::Timeout`](crate::Error::Timeout).
+    pub fn command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = Some(timeout);
+        self
+    }
+
+    /// Enables automatic reconnection following the given policy.
+    pub fn reconnection_policy(mut self, policy: ReconnectionPolicy) -> Self {
+        self.reconnection_policy = policy;
+        self
+    }
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn pseudo_random_fraction() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+
+    (nanos % 1000) as f64 / 1000.0
+}
@@ -0,0 +1,290 @@
+use crate::{
+    resp::{cmd, BulkString, SingleArgOrCollection},
+    PreparedCommand,
+};
+
+/// A group of Redis commands related to [`RedisBloom`](https://redis.io/docs/data-types/probabilistic/bloom-filter/) bloom filters
+///
+/// # See Also
+/// [RedisBloom Command Reference](https://redis.io/commands/?group=bf)
+pub trait BloomCommands {
+    /// Creates an empty bloom filter with a given capacity and error rate.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/bf.reserve/>]
+    fn bf_reserve<K>(
+        &mut self,
+        key: K,
+        error_rate: f64,
+        capacity: usize,
+    ) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("BF.RESERVE").arg(key).arg(error_rate).arg(capacity))
+    }
+
+    /// Adds `item` to the bloom filter at `key`, creating it with default parameters if needed.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/bf.add/>]
+    fn bf_add<K, I>(&mut self, key: K, item: I) -> PreparedCommand<Self, bool>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("BF.ADD").arg(key).arg(item))
+    }
+
+    /// Checks whether `item` may have been added to the bloom filter at `key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/bf.exists/>]
+    fn bf_exists<K, I>(&mut self, key: K, item: I) -> PreparedCommand<Self, bool>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("BF.EXISTS").arg(key).arg(item))
+    }
+}
+
+/// A group of Redis commands related to [`RedisBloom`](https://redis.io/docs/data-types/probabilistic/cuckoo-filter/) cuckoo filters
+///
+/// # See Also
+/// [RedisBloom Command Reference](https://redis.io/commands/?group=cf)
+pub trait CuckooCommands {
+    /// Creates an empty cuckoo filter with a given capacity.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cf.reserve/>]
+    fn cf_reserve<K>(&mut self, key: K, capacity: usize) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("CF.RESERVE").arg(key).arg(capacity))
+    }
+
+    /// Adds `item` to the cuckoo filter at `key`, creating it with default parameters if needed.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cf.add/>]
+    fn cf_add<K, I>(&mut self, key: K, item: I) -> PreparedCommand<Self, bool>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("CF.ADD").arg(key).arg(item))
+    }
+
+    /// Checks whether `item` may have been added to the cuckoo filter at `key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cf.exists/>]
+    fn cf_exists<K, I>(&mut self, key: K, item: I) -> PreparedCommand<Self, bool>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("CF.EXISTS").arg(key).arg(item))
+    }
+
+    /// Removes `item` from the cuckoo filter at `key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cf.del/>]
+    fn cf_del<K, I>(&mut self, key: K, item: I) -> PreparedCommand<Self, bool>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("CF.DEL").arg(key).arg(item))
+    }
+}
+
+/// A group of Redis commands related to [`RedisBloom`](https://redis.io/docs/data-types/probabilistic/count-min-sketch/) count-min sketches
+///
+/// # See Also
+/// [RedisBloom Command Reference](https://redis.io/commands/?group=cms)
+pub trait CountMinSketchCommands {
+    /// Creates an empty count-min sketch with the given dimensions.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cms.initbydim/>]
+    fn cms_initbydim<K>(&mut self, key: K, width: usize, depth: usize) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("CMS.INITBYDIM").arg(key).arg(width).arg(depth))
+    }
+
+    /// Increments the count of `item` in the sketch at `key` by `increment`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cms.incrby/>]
+    fn cms_incrby<K, I>(&mut self, key: K, item: I, increment: i64) -> PreparedCommand<Self, i64>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("CMS.INCRBY").arg(key).arg(item).arg(increment))
+    }
+
+    /// Returns the estimated count of each of `items` in the sketch at `key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/cms.query/>]
+    fn cms_query<K, I, II>(&mut self, key: K, items: II) -> PreparedCommand<Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+        II: SingleArgOrCollection<I>,
+    {
+        PreparedCommand::new(self, cmd("CMS.QUERY").arg(key).arg(items))
+    }
+}
+
+/// A group of Redis commands related to [`RedisBloom`](https://redis.io/docs/data-types/probabilistic/t-digest/) t-digest sketches
+///
+/// # See Also
+/// [RedisBloom Command Reference](https://redis.io/commands/?group=tdigest)
+pub trait TDigestCommands {
+    /// Creates an empty t-digest sketch.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/tdigest.create/>]
+    fn tdigest_create<K>(&mut self, key: K) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("TDIGEST.CREATE").arg(key))
+    }
+
+    /// Adds `values` to the t-digest sketch at `key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/tdigest.add/>]
+    fn tdigest_add<K, VV>(&mut self, key: K, values: VV) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        VV: SingleArgOrCollection<f64>,
+    {
+        PreparedCommand::new(self, cmd("TDIGEST.ADD").arg(key).arg(values))
+    }
+
+    /// Returns the estimated value at `quantile` (between 0 and 1) of the t-digest sketch at `key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/tdigest.quantile/>]
+    fn tdigest_quantile<K>(&mut self, key: K, quantile: f64) -> PreparedCommand<Self, Vec<f64>>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("TDIGEST.QUANTILE").arg(key).arg(quantile))
+    }
+}
+
+/// A group of Redis commands related to [`RedisBloom`](https://redis.io/docs/data-types/probabilistic/top-k/) top-k filters
+///
+/// # See Also
+/// [RedisBloom Command Reference](https://redis.io/commands/?group=topk)
+pub trait TopKCommands {
+    /// Creates an empty top-k filter keeping track of the `k` heaviest hitters.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/topk.reserve/>]
+    fn topk_reserve<K>(&mut self, key: K, k: usize) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("TOPK.RESERVE").arg(key).arg(k))
+    }
+
+    /// Adds `items` to the top-k filter at `key`, returning any item each one evicted.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/topk.add/>]
+    fn topk_add<K, I, II>(&mut self, key: K, items: II) -> PreparedCommand<Self, Vec<Option<String>>>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        I: Into<BulkString> + Send,
+        II: SingleArgOrCollection<I>,
+    {
+        PreparedCommand::new(self, cmd("TOPK.ADD").arg(key).arg(items))
+    }
+
+    /// Lists the current heaviest hitters tracked by the top-k filter at `key`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/topk.list/>]
+    fn topk_list<K>(&mut self, key: K) -> PreparedCommand<Self, Vec<String>>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("TOPK.LIST").arg(key))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    resp::{cmd, BulkString, FromValue, SingleArgOrCollection},
+    PreparedCommand,
+};
+
+/// A group of Redis commands related to [`RedisJSON`](https://redis.io/docs/data-types/json/)
+///
+/// # See Also
+/// [RedisJSON Command Reference](https://redis.io/commands/?group=json)
+pub trait JsonCommands {
+    /// Sets the JSON value at `path` in `key`.
+    ///
+    /// # Arguments
+    /// * `key` - The key holding the JSON document.
+    /// * `path` - JSONPath at which to set the value, e.g. `$` for the document root.
+    /// * `value` - JSON-encoded value to store.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/json.set/>]
+    fn json_set<K, P, V>(&mut self, key: K, path: P, value: V) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        P: Into<BulkString> + Send,
+        V: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("JSON.SET").arg(key).arg(path).arg(value))
+    }
+
+    /// Gets the JSON value(s) at the given `paths` in `key`.
+    ///
+    /// # Arguments
+    /// * `key` - The key holding the JSON document.
+    /// * `paths` - One or several JSONPaths to read.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/json.get/>]
+    fn json_get<K, P, PP, R>(&mut self, key: K, paths: PP) -> PreparedCommand<Self, R>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        P: Into<BulkString> + Send,
+        PP: SingleArgOrCollection<P>,
+        R: FromValue,
+    {
+        PreparedCommand::new(self, cmd("JSON.GET").arg(key).arg(paths))
+    }
+
+    /// Appends one or more values to the array at `path` in `key`.
+    ///
+    /// # Arguments
+    /// * `key` - The key holding the JSON document.
+    /// * `path` - JSONPath of the array.
+    /// * `values` - JSON-encoded values to append.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/json.arrappend/>]
+    fn json_arrappend<K, P, V, VV>(
+        &mut self,
+        key: K,
+        path: P,
+        values: VV,
+    ) -> PreparedCommand<Self, Vec<usize>>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        P: Into<BulkString> + Send,
+        V: Into<BulkString> + Send,
+        VV: SingleArgOrCollection<V>,
+    {
+        PreparedCommand::new(self, cmd("JSON.ARRAPPEND").arg(key).arg(path).arg(values))
+    }
+
+    /// Increments the number at `path` in `key` by `value`.
+    ///
+    /// # Arguments
+    /// * `key` - The key holding the JSON document.
+    /// * `path` - JSONPath of the number to increment.
+    /// * `value` - Increment to apply.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/json.numincrby/>]
+    fn json_numincrby<K, P, R>(&mut self, key: K, path: P, value: f64) -> PreparedCommand<Self, R>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        P: Into<BulkString> + Send,
+        R: FromValue,
+    {
+        PreparedCommand::new(self, cmd("JSON.NUMINCRBY").arg(key).arg(path).arg(value))
+    }
+}
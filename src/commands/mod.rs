@@ -0,0 +1,17 @@
+#[cfg(feature = "redis-bloom")]
+mod bloom_commands;
+#[cfg(feature = "redis-json")]
+mod json_commands;
+#[cfg(feature = "redis-search")]
+mod search_commands;
+#[cfg(feature = "redis-time-series")]
+mod time_series_commands;
+
+#[cfg(feature = "redis-bloom")]
+pub use bloom_commands::*;
+#[cfg(feature = "redis-json")]
+pub use json_commands::*;
+#[cfg(feature = "redis-search")]
+pub use search_commands::*;
+#[cfg(feature = "redis-time-series")]
+pub use time_series_commands::*;
@@ -0,0 +1,186 @@
+use crate::{
+    resp::{cmd, BulkString, FromValue, SingleArgOrCollection},
+    PreparedCommand,
+};
+
+/// The type of a field declared in an [`ft_create`](SearchCommands::ft_create) index schema.
+///
+/// # See Also
+/// [<https://redis.io/commands/ft.create/>]
+pub enum FieldType {
+    Text,
+    Tag,
+    Numeric,
+    Geo,
+    Vector,
+}
+
+impl From<FieldType> for BulkString {
+    fn from(field_type: FieldType) -> Self {
+        match field_type {
+            FieldType::Text => "TEXT".into(),
+            FieldType::Tag => "TAG".into(),
+            FieldType::Numeric => "NUMERIC".into(),
+            FieldType::Geo => "GEO".into(),
+            FieldType::Vector => "VECTOR".into(),
+        }
+    }
+}
+
+/// A single `name TYPE` pair of an [`ft_create`](SearchCommands::ft_create) index schema.
+///
+/// # See Also
+/// [<https://redis.io/commands/ft.create/>]
+pub struct FieldSchema {
+    pub name: String,
+    pub field_type: FieldType,
+}
+
+impl FieldSchema {
+    pub fn new(name: impl Into<String>, field_type: FieldType) -> Self {
+        Self {
+            name: name.into(),
+            field_type,
+        }
+    }
+}
+
+/// A parsed `FT.SEARCH` result: the total number of matching documents
+/// followed by the matching documents themselves, each as a list of `field value` pairs.
+///
+/// # See Also
+/// [<https://redis.io/commands/ft.search/>]
+pub struct SearchResult {
+    pub total: usize,
+    pub documents: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl FromValue for SearchResult {
+    fn from_value(value: crate::resp::Value) -> crate::Result<Self> {
+        let values: Vec<crate::resp::Value> = value.into()?;
+        let mut iter = values.into_iter();
+        let total: usize = iter.next().map(FromValue::from_value).transpose()?.unwrap_or(0);
+        let mut documents = Vec::new();
+
+        while let (Some(id), Some(fields)) = (iter.next(), iter.next()) {
+            let id: String = id.into()?;
+            let fields: Vec<String> = fields.into()?;
+            let fields = fields
+                .chunks_exact(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone()))
+                .collect();
+            documents.push((id, fields));
+        }
+
+        Ok(Self { total, documents })
+    }
+}
+
+/// A parsed `FT.AGGREGATE` result: the rows produced by the aggregation pipeline, each as a list
+/// of `field value` pairs.
+///
+/// Unlike [`SearchResult`], an aggregate reply carries neither a leading total count nor a
+/// document id per row, so it cannot reuse [`SearchResult`]'s parsing.
+///
+/// # See Also
+/// [<https://redis.io/commands/ft.aggregate/>]
+pub struct AggregateResult {
+    pub rows: Vec<Vec<(String, String)>>,
+}
+
+impl FromValue for AggregateResult {
+    fn from_value(value: crate::resp::Value) -> crate::Result<Self> {
+        let values: Vec<crate::resp::Value> = value.into()?;
+        let rows = values
+            .into_iter()
+            .map(|row| {
+                let fields: Vec<String> = row.into()?;
+                Ok(fields
+                    .chunks_exact(2)
+                    .map(|pair| (pair[0].clone(), pair[1].clone()))
+                    .collect())
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Self { rows })
+    }
+}
+
+/// A group of Redis commands related to [`RediSearch`](https://redis.io/docs/interact/search-and-query/)
+///
+/// # See Also
+/// [RediSearch Command Reference](https://redis.io/commands/?group=search)
+pub trait SearchCommands {
+    /// Creates an index with the given field schema.
+    ///
+    /// # Arguments
+    /// * `index` - Name of the index to create.
+    /// * `schema` - Ordered list of field declarations making up the index schema.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/ft.create/>]
+    fn ft_create<N>(&mut self, index: N, schema: Vec<FieldSchema>) -> PreparedCommand<Self, ()>
+    where
+        Self: Sized,
+        N: Into<BulkString> + Send,
+    {
+        let mut command = cmd("FT.CREATE").arg(index).arg("SCHEMA");
+
+        for field in schema {
+            command = command.arg(field.name).arg(field.field_type);
+        }
+
+        PreparedCommand::new(self, command)
+    }
+
+    /// Searches `index` with a query string.
+    ///
+    /// # Arguments
+    /// * `index` - Name of the index to search.
+    /// * `query` - RediSearch query string.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/ft.search/>]
+    fn ft_search<N, Q>(&mut self, index: N, query: Q) -> PreparedCommand<Self, SearchResult>
+    where
+        Self: Sized,
+        N: Into<BulkString> + Send,
+        Q: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("FT.SEARCH").arg(index).arg(query))
+    }
+
+    /// Runs an aggregation pipeline over `index`.
+    ///
+    /// # Arguments
+    /// * `index` - Name of the index to aggregate over.
+    /// * `query` - RediSearch query string selecting the documents to aggregate.
+    /// * `pipeline` - Raw `FT.AGGREGATE` pipeline arguments (`GROUPBY`, `REDUCE`, `SORTBY`, ...).
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/ft.aggregate/>]
+    fn ft_aggregate<N, Q, A, AA>(
+        &mut self,
+        index: N,
+        query: Q,
+        pipeline: AA,
+    ) -> PreparedCommand<Self, AggregateResult>
+    where
+        Self: Sized,
+        N: Into<BulkString> + Send,
+        Q: Into<BulkString> + Send,
+        A: Into<BulkString> + Send,
+        AA: SingleArgOrCollection<A>,
+    {
+        PreparedCommand::new(self, cmd("FT.AGGREGATE").arg(index).arg(query).arg(pipeline))
+    }
+}
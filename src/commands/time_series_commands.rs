@@ -0,0 +1,108 @@
+use crate::{
+    resp::{cmd, BulkString, FromValue, SingleArgOrCollection, Value},
+    PreparedCommand,
+};
+
+/// A single time series sample: a millisecond UNIX timestamp paired with its value.
+pub type Sample = (u64, f64);
+
+/// A single time series matched by [`ts_mrange`](TimeSeriesCommands::ts_mrange): its key and the
+/// samples within the requested range.
+///
+/// A real `TS.MRANGE` reply is keyed per matching series (`key`, labels, samples); this keeps the
+/// key so callers can tell which series each sample belongs to, instead of flattening every
+/// series into a single, unattributed list.
+///
+/// # See Also
+/// [<https://redis.io/commands/ts.mrange/>]
+pub struct MRangeEntry {
+    pub key: String,
+    pub samples: Vec<Sample>,
+}
+
+impl FromValue for MRangeEntry {
+    fn from_value(value: Value) -> crate::Result<Self> {
+        let mut fields: Vec<Value> = value.into()?;
+        let samples: Vec<Sample> = fields.pop().unwrap_or(Value::Array(Vec::new())).into()?;
+        // `.into()` on a missing/`Nil` key surfaces a type-conversion `Result::Err` instead of
+        // panicking on a short or malformed `TS.MRANGE` entry.
+        let key: String = fields.first().cloned().unwrap_or(Value::Nil).into()?;
+        Ok(Self { key, samples })
+    }
+}
+
+/// A group of Redis commands related to [`RedisTimeSeries`](https://redis.io/docs/data-types/timeseries/)
+///
+/// # See Also
+/// [RedisTimeSeries Command Reference](https://redis.io/commands/?group=timeseries)
+pub trait TimeSeriesCommands {
+    /// Appends a sample to a time series, creating it if it does not already exist.
+    ///
+    /// # Arguments
+    /// * `key` - Name of the time series.
+    /// * `timestamp` - Millisecond UNIX timestamp, or `*` for the server's current time.
+    /// * `value` - Sample value.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/ts.add/>]
+    fn ts_add<K, T>(&mut self, key: K, timestamp: T, value: f64) -> PreparedCommand<Self, u64>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+        T: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("TS.ADD").arg(key).arg(timestamp).arg(value))
+    }
+
+    /// Returns the samples of a time series between `from` and `to`, both millisecond UNIX timestamps.
+    ///
+    /// # Arguments
+    /// * `key` - Name of the time series.
+    /// * `from` - Start timestamp, inclusive.
+    /// * `to` - End timestamp, inclusive.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/ts.range/>]
+    fn ts_range<K>(&mut self, key: K, from: i64, to: i64) -> PreparedCommand<Self, Vec<Sample>>
+    where
+        Self: Sized,
+        K: Into<BulkString> + Send,
+    {
+        PreparedCommand::new(self, cmd("TS.RANGE").arg(key).arg(from).arg(to))
+    }
+
+    /// Returns the samples of every time series matching `filters`, between `from` and `to`.
+    ///
+    /// # Arguments
+    /// * `from` - Start timestamp, inclusive.
+    /// * `to` - End timestamp, inclusive.
+    /// * `filters` - Label filter expressions selecting the time series, e.g. `sensor=humidity`.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/ts.mrange/>]
+    fn ts_mrange<F, FF>(
+        &mut self,
+        from: i64,
+        to: i64,
+        filters: FF,
+    ) -> PreparedCommand<Self, Vec<MRangeEntry>>
+    where
+        Self: Sized,
+        F: Into<BulkString> + Send,
+        FF: SingleArgOrCollection<F>,
+    {
+        PreparedCommand::new(
+            self,
+            cmd("TS.MRANGE").arg(from).arg(to).arg("FILTER").arg(filters),
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    resp::Value,
+    Cache, CacheConfig,
+};
+
+fn value(s: &str) -> Value {
+    Value::BulkString(s.into())
+}
+
+#[test]
+fn insert_evicts_oldest_entry_once_max_entries_is_reached() {
+    let cache = Cache::new(CacheConfig::new().max_entries(2));
+
+    cache.insert("a".into(), value("1"));
+    cache.insert("b".into(), value("2"));
+    cache.insert("c".into(), value("3"));
+
+    assert!(cache.get(&"a".into()).is_none());
+    assert!(cache.get(&"b".into()).is_some());
+    assert!(cache.get(&"c".into()).is_some());
+}
+
+#[test]
+fn reinserting_a_key_refreshes_its_eviction_order_instead_of_duplicating_it() {
+    let cache = Cache::new(CacheConfig::new().max_entries(2));
+
+    cache.insert("a".into(), value("1"));
+    cache.insert("b".into(), value("2"));
+    cache.insert("a".into(), value("1-again"));
+    cache.insert("c".into(), value("3"));
+
+    // "a" was refreshed after "b", so "b" is the oldest and must be the one evicted.
+    assert!(cache.get(&"a".into()).is_some());
+    assert!(cache.get(&"b".into()).is_none());
+    assert!(cache.get(&"c".into()).is_some());
+}
+
+#[test]
+fn invalidate_with_keys_only_evicts_those_keys() {
+    let cache = Cache::new(CacheConfig::new());
+
+    cache.insert("a".into(), value("1"));
+    cache.insert("b".into(), value("2"));
+
+    cache.invalidate(Some(vec!["a".into()]));
+
+    assert!(cache.get(&"a".into()).is_none());
+    assert!(cache.get(&"b".into()).is_some());
+}
+
+#[test]
+fn invalidate_with_none_clears_the_whole_cache() {
+    let cache = Cache::new(CacheConfig::new());
+
+    cache.insert("a".into(), value("1"));
+    cache.insert("b".into(), value("2"));
+
+    cache.invalidate(None);
+
+    assert!(cache.get(&"a".into()).is_none());
+    assert!(cache.get(&"b".into()).is_none());
+}
+
+#[test]
+fn invalidate_racing_ahead_of_an_in_flight_fetch_drops_the_late_insert() {
+    let cache = Cache::new(CacheConfig::new());
+
+    cache.begin_fetch(&"a".into());
+    // The invalidation for "a" arrives while the read that populates it is still in flight.
+    cache.invalidate(Some(vec!["a".into()]));
+    cache.insert("a".into(), value("stale"));
+
+    assert!(cache.get(&"a".into()).is_none());
+}
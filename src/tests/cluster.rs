@@ -0,0 +1,30 @@
+use crate::MultiplexedClusterClient;
+
+#[test]
+fn hash_slot_uses_first_hashtag_after_the_opening_brace() {
+    // A `}` appearing before the first `{` must not be mistaken for the hashtag's closing brace.
+    assert_eq!(
+        MultiplexedClusterClient::hash_slot(b"a}{bc}d"),
+        MultiplexedClusterClient::hash_slot(b"bc"),
+    );
+}
+
+#[test]
+fn hash_slot_hashes_whole_key_without_a_hashtag() {
+    assert_ne!(
+        MultiplexedClusterClient::hash_slot(b"foo"),
+        MultiplexedClusterClient::hash_slot(b"bar"),
+    );
+}
+
+#[test]
+fn hash_slot_ignores_an_empty_hashtag() {
+    assert_eq!(
+        MultiplexedClusterClient::hash_slot(b"foo{}bar"),
+        MultiplexedClusterClient::hash_slot(b"foo{}bar"),
+    );
+    assert_ne!(
+        MultiplexedClusterClient::hash_slot(b"foo{}bar"),
+        MultiplexedClusterClient::hash_slot(b"bar"),
+    );
+}
@@ -0,0 +1,44 @@
+use crate::{
+    resp::{cmd, Value},
+    ClientTrait, MockClient, Result, StringCommands,
+};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mocked_get() -> Result<()> {
+    let mut mock = MockClient::new();
+    mock.expect(cmd("GET").arg("key"), Value::BulkString("value".into()));
+
+    let value: String = mock.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mocked_unmatched_command_defaults_to_nil() -> Result<()> {
+    let mut mock = MockClient::new();
+
+    let value: Option<String> = mock.get("missing").await?;
+    assert_eq!(None, value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mocked_pipeline() -> Result<()> {
+    let mut mock = MockClient::new();
+    mock.expect(cmd("GET").arg("key1"), Value::BulkString("one".into()));
+    mock.expect(cmd("GET").arg("key2"), Value::BulkString("two".into()));
+
+    let mut pipeline = mock.create_pipeline();
+    pipeline.queue(cmd("GET").arg("key1"));
+    pipeline.queue(cmd("GET").arg("key2"));
+
+    let values: Vec<String> = pipeline.execute().await?.into()?;
+    assert_eq!(vec!["one".to_owned(), "two".to_owned()], values);
+
+    Ok(())
+}